@@ -19,6 +19,7 @@ mod parse;
 mod print;
 #[cfg(any(test, all(feature = "chrono", feature = "serde")))]
 mod serde;
+mod timezone;
 
 // We treat chrono-tz as optional on top of chrono.
 #[cfg(not(any(feature = "math", feature = "chrono", feature = "serde")))]
@@ -32,14 +33,45 @@ pub use crate::math::{
   tomorrow,
 };
 
+#[cfg(all(feature = "math", feature = "chrono"))]
+pub use crate::math::{
+  add_days,
+  add_months,
+  start_of_day,
+  start_of_day_in,
+  start_of_month,
+  start_of_month_in,
+  start_of_week,
+  start_of_week_in,
+  start_of_year,
+  start_of_year_in,
+};
+
+pub use crate::timezone::{
+  FixedOffset,
+  Offset,
+  TimeZone,
+  EST,
+  UTC,
+};
+
+#[cfg(feature = "chrono")]
+pub use crate::timezone::parse_offset;
+
 #[cfg(feature = "chrono")]
 pub use crate::parse::{
   parse_system_time_from_date_str,
+  parse_system_time_from_rfc2822,
   parse_system_time_from_str,
 };
 
 #[cfg(feature = "chrono")]
-pub use crate::print::print_system_time_to_rfc3339;
+pub use crate::print::{
+  print_system_time_to_rfc2822,
+  print_system_time_to_rfc3339,
+  print_system_time_to_rfc3339_opts,
+  Precision,
+};
 
 #[cfg(all(feature = "chrono", feature = "serde"))]
 pub use crate::serde::{
@@ -47,14 +79,23 @@ pub use crate::serde::{
   optional_system_time_to_rfc3339,
   system_time_from_date_str,
   system_time_from_millis,
+  system_time_from_rfc2822,
   system_time_from_secs,
+  system_time_from_signed_millis,
+  system_time_from_signed_secs,
   system_time_from_str,
   system_time_to_millis,
+  system_time_to_rfc2822,
   system_time_to_rfc3339,
+  system_time_to_rfc3339_secs,
+  system_time_to_signed_millis,
+  system_time_to_signed_secs,
 };
 
 #[cfg(all(feature = "chrono", feature = "chrono-tz", feature = "serde"))]
 pub use crate::serde::{
   system_time_from_millis_in_new_york,
+  system_time_from_millis_in_zone,
   system_time_to_millis_in_new_york,
+  system_time_to_millis_in_zone,
 };
@@ -17,17 +17,24 @@ fn next_day_duration(now: SystemTime) -> Duration {
   // there is no way `SystemTime::duration_since` can ever fail with it
   // as a parameter.
   let duration = now.duration_since(UNIX_EPOCH).unwrap();
-  let next_day = round_up(duration.as_secs(), DAY_SECS.into());
-  let duration = Duration::from_secs(next_day);
-  duration
+  let secs = duration.as_secs();
+  let next_day = round_up(secs, DAY_SECS.into());
+  // `round_up` leaves a value that is already a multiple of `DAY_SECS`
+  // untouched, so a time stamp marking precisely midnight would not
+  // advance. Nudge it onto the following day explicitly.
+  let next_day = if next_day == secs {
+    next_day + u64::from(DAY_SECS)
+  } else {
+    next_day
+  };
+  Duration::from_secs(next_day)
 }
 
 /// Calculate the time stamp representing the next day of the given time
 /// stamp.
 ///
-/// Note that currently a time stamp marking precisely midnight will not
-/// advance to the next day.
-// TODO: We should fix this behavior.
+/// A time stamp marking precisely midnight advances to the following
+/// day.
 pub fn next_day(now: SystemTime) -> SystemTime {
   let duration = next_day_duration(now);
   UNIX_EPOCH + duration
@@ -49,6 +56,143 @@ pub fn tomorrow() -> SystemTime {
 }
 
 
+/// Calendar-aware date arithmetic.
+///
+/// In contrast to the plain second-rounding performed by [`next_day`] &
+/// friends, these operations go through chrono's `Datelike` machinery
+/// and so respect month lengths, leap years, and week boundaries. They
+/// operate in UTC, matching the crate's "`SystemTime` is UTC"
+/// convention; the `*_in` counterparts reinterpret the wall clock in a
+/// [`TimeZone`](crate::timezone::TimeZone) first.
+#[cfg(feature = "chrono")]
+mod calendar {
+  use std::time::SystemTime;
+
+  use chrono::offset::TimeZone as _;
+  use chrono::offset::Utc;
+  use chrono::DateTime;
+  use chrono::Datelike as _;
+  use chrono::Duration;
+  use chrono::NaiveDate;
+  use chrono::Weekday;
+
+  use crate::timezone::TimeZone;
+
+
+  fn last_day_of_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+      (year + 1, 1)
+    } else {
+      (year, month + 1)
+    };
+    NaiveDate::from_ymd(next_year, next_month, 1).pred().day()
+  }
+
+  /// Calculate the start (0:00:00) of the day containing `time`.
+  pub fn start_of_day(time: &SystemTime) -> SystemTime {
+    let datetime = DateTime::<Utc>::from(*time);
+    SystemTime::from(datetime.date().and_hms(0, 0, 0))
+  }
+
+  /// Calculate the start (0:00:00) of the week containing `time`, with
+  /// `week_start` naming the weekday the week is considered to begin on.
+  pub fn start_of_week(time: &SystemTime, week_start: Weekday) -> SystemTime {
+    let datetime = DateTime::<Utc>::from(*time);
+    let offset = i64::from(
+      (7 + datetime.weekday().num_days_from_monday() - week_start.num_days_from_monday()) % 7,
+    );
+    let date = datetime.date() - Duration::days(offset);
+    SystemTime::from(date.and_hms(0, 0, 0))
+  }
+
+  /// Calculate the start (0:00:00 on the first) of the month containing
+  /// `time`.
+  pub fn start_of_month(time: &SystemTime) -> SystemTime {
+    let datetime = DateTime::<Utc>::from(*time).with_day(1).unwrap();
+    SystemTime::from(datetime.date().and_hms(0, 0, 0))
+  }
+
+  /// Calculate the start (0:00:00 on January 1st) of the year containing
+  /// `time`.
+  pub fn start_of_year(time: &SystemTime) -> SystemTime {
+    let datetime = DateTime::<Utc>::from(*time).with_ordinal(1).unwrap();
+    SystemTime::from(datetime.date().and_hms(0, 0, 0))
+  }
+
+  /// Advance `time` by the given (possibly negative) number of calendar
+  /// days, preserving the time of day.
+  pub fn add_days(time: &SystemTime, days: i64) -> SystemTime {
+    let datetime = DateTime::<Utc>::from(*time);
+    let date = datetime.date() + Duration::days(days);
+    SystemTime::from(date.and_time(datetime.time()).unwrap())
+  }
+
+  /// Advance `time` by the given (possibly negative) number of calendar
+  /// months, preserving the time of day and clamping the day of month to
+  /// the last valid day (so 2020-01-31 + 1 month yields 2020-02-29).
+  pub fn add_months(time: &SystemTime, months: i32) -> SystemTime {
+    let datetime = DateTime::<Utc>::from(*time);
+    let date = datetime.date();
+    let total = date.year() * 12 + date.month0() as i32 + months;
+    let year = total.div_euclid(12);
+    let month = total.rem_euclid(12) as u32 + 1;
+    let day = date.day().min(last_day_of_month(year, month));
+    let date = Utc.ymd(year, month, day);
+    SystemTime::from(date.and_time(datetime.time()).unwrap())
+  }
+
+  /// Calculate the start of the day containing `time` relative to the
+  /// time zone `Tz`.
+  pub fn start_of_day_in<Tz>(time: &SystemTime) -> SystemTime
+  where
+    Tz: TimeZone,
+  {
+    Tz::sub(start_of_day(&Tz::add(*time)))
+  }
+
+  /// Calculate the start of the week containing `time` relative to the
+  /// time zone `Tz`.
+  pub fn start_of_week_in<Tz>(time: &SystemTime, week_start: Weekday) -> SystemTime
+  where
+    Tz: TimeZone,
+  {
+    Tz::sub(start_of_week(&Tz::add(*time), week_start))
+  }
+
+  /// Calculate the start of the month containing `time` relative to the
+  /// time zone `Tz`.
+  pub fn start_of_month_in<Tz>(time: &SystemTime) -> SystemTime
+  where
+    Tz: TimeZone,
+  {
+    Tz::sub(start_of_month(&Tz::add(*time)))
+  }
+
+  /// Calculate the start of the year containing `time` relative to the
+  /// time zone `Tz`.
+  pub fn start_of_year_in<Tz>(time: &SystemTime) -> SystemTime
+  where
+    Tz: TimeZone,
+  {
+    Tz::sub(start_of_year(&Tz::add(*time)))
+  }
+}
+
+#[cfg(feature = "chrono")]
+pub use self::calendar::{
+  add_days,
+  add_months,
+  start_of_day,
+  start_of_day_in,
+  start_of_month,
+  start_of_month_in,
+  start_of_week,
+  start_of_week_in,
+  start_of_year,
+  start_of_year_in,
+};
+
+
 #[cfg(test)]
 pub mod tests {
   use super::*;
@@ -65,6 +209,11 @@ pub mod tests {
     let now = parse_system_time_from_str("2019-03-31T23:59:59Z").unwrap();
     let tomorrow = parse_system_time_from_str("2019-04-01T00:00:00Z").unwrap();
     assert_eq!(next_day(now), tomorrow);
+
+    // A time stamp marking precisely midnight advances to the next day.
+    let now = parse_system_time_from_str("2020-02-07T00:00:00Z").unwrap();
+    let tomorrow = parse_system_time_from_str("2020-02-08T00:00:00Z").unwrap();
+    assert_eq!(next_day(now), tomorrow);
   }
 
   #[test]
@@ -77,4 +226,80 @@ pub mod tests {
     let five_ago = parse_system_time_from_str("2020-01-28T00:00:00Z").unwrap();
     assert_eq!(days_back_from(now, 5), five_ago);
   }
+
+  #[cfg(feature = "chrono")]
+  mod calendar {
+    use super::*;
+
+    use chrono::Weekday;
+
+    use crate::timezone::EST;
+
+
+    #[test]
+    fn starts() {
+      let now = parse_system_time_from_str("2020-02-07T13:37:00Z").unwrap();
+
+      assert_eq!(
+        start_of_day(&now),
+        parse_system_time_from_str("2020-02-07T00:00:00Z").unwrap()
+      );
+      // 2020-02-07 is a Friday.
+      assert_eq!(
+        start_of_week(&now, Weekday::Mon),
+        parse_system_time_from_str("2020-02-03T00:00:00Z").unwrap()
+      );
+      assert_eq!(
+        start_of_week(&now, Weekday::Sun),
+        parse_system_time_from_str("2020-02-02T00:00:00Z").unwrap()
+      );
+      assert_eq!(
+        start_of_month(&now),
+        parse_system_time_from_str("2020-02-01T00:00:00Z").unwrap()
+      );
+      assert_eq!(
+        start_of_year(&now),
+        parse_system_time_from_str("2020-01-01T00:00:00Z").unwrap()
+      );
+    }
+
+    #[test]
+    fn add_calendar_days() {
+      let now = parse_system_time_from_str("2020-02-28T13:37:00Z").unwrap();
+      assert_eq!(
+        add_days(&now, 1),
+        parse_system_time_from_str("2020-02-29T13:37:00Z").unwrap()
+      );
+      assert_eq!(
+        add_days(&now, -28),
+        parse_system_time_from_str("2020-01-31T13:37:00Z").unwrap()
+      );
+    }
+
+    #[test]
+    fn add_calendar_months_clamps() {
+      let now = parse_system_time_from_str("2020-01-31T13:37:00Z").unwrap();
+      // February 2020 only has 29 days, so the day is clamped.
+      assert_eq!(
+        add_months(&now, 1),
+        parse_system_time_from_str("2020-02-29T13:37:00Z").unwrap()
+      );
+      // Rolling over a year boundary backwards.
+      assert_eq!(
+        add_months(&now, -1),
+        parse_system_time_from_str("2019-12-31T13:37:00Z").unwrap()
+      );
+    }
+
+    #[test]
+    fn start_of_day_in_zone() {
+      // 2020-02-07T02:00:00Z is still 2020-02-06 in EST (UTC-5), so the
+      // start of the EST day is 2020-02-06T05:00:00Z.
+      let now = parse_system_time_from_str("2020-02-07T02:00:00Z").unwrap();
+      assert_eq!(
+        start_of_day_in::<EST>(&now),
+        parse_system_time_from_str("2020-02-06T05:00:00Z").unwrap()
+      );
+    }
+  }
 }
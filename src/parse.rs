@@ -14,10 +14,21 @@ type DateFn = fn(&str) -> Result<DateTime<FixedOffset>, ParseError>;
 
 
 /// The list of time stamp formats we support.
-pub(crate) const TIME_PARSE_FNS: [DateFn; 3] = [
+///
+/// In addition to the `T`-separated RFC3339 forms we also accept the
+/// variants using a single ASCII space in place of the `T`, as emitted
+/// by many databases and log tools, as well as the offset-less local
+/// forms, which we interpret as UTC in keeping with the crate's
+/// "`SystemTime` is UTC" convention.
+pub(crate) const TIME_PARSE_FNS: [DateFn; 8] = [
   |s| FixedOffset::east(0).datetime_from_str(s, "%Y-%m-%dT%H:%M:%S%.fZ"),
   |s| FixedOffset::east(0).datetime_from_str(s, "%Y-%m-%dT%H:%M:%SZ"),
   |s| DateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f%z"),
+  |s| FixedOffset::east(0).datetime_from_str(s, "%Y-%m-%d %H:%M:%S%.fZ"),
+  |s| FixedOffset::east(0).datetime_from_str(s, "%Y-%m-%d %H:%M:%SZ"),
+  |s| DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f%z"),
+  |s| FixedOffset::east(0).datetime_from_str(s, "%Y-%m-%dT%H:%M:%S%.f"),
+  |s| FixedOffset::east(0).datetime_from_str(s, "%Y-%m-%d %H:%M:%S%.f"),
 ];
 
 pub(crate) const DATE_PARSE_FNS: [DateFn; 1] = [|s| {
@@ -25,6 +36,9 @@ pub(crate) const DATE_PARSE_FNS: [DateFn; 1] = [|s| {
     .map(|date| DateTime::from_utc(date.and_hms(0, 0, 0), FixedOffset::east(0)))
 }];
 
+/// The list of RFC 2822 time stamp formats we support.
+pub(crate) const RFC2822_PARSE_FNS: [DateFn; 1] = [DateTime::parse_from_rfc2822];
+
 
 /// Parse a `SystemTime` from a string using any of the provided parsing
 /// functions.
@@ -59,6 +73,17 @@ pub fn parse_system_time_from_date_str(time: &str) -> Option<SystemTime> {
 }
 
 
+/// Parse a `SystemTime` from an RFC 2822 time stamp, such as those
+/// found in HTTP or email headers (e.g. `Sun, 01 Apr 2018 12:04:17
+/// -0500`).
+///
+/// The "negative UTC" offset form (`-0000`) is accepted and treated as
+/// UTC.
+pub fn parse_system_time_from_rfc2822(time: &str) -> Option<SystemTime> {
+  parse_system_time_from_str_impl(&time, &RFC2822_PARSE_FNS)
+}
+
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -80,4 +105,45 @@ mod tests {
     let expected = UNIX_EPOCH + Duration::from_secs(1_564_617_600);
     assert_eq!(time, expected)
   }
+
+  #[test]
+  fn parse_space_separated() {
+    let expected = UNIX_EPOCH + Duration::from_secs(1_522_584_000);
+    for string in &[
+      "2018-04-01 12:00:00Z",
+      "2018-04-01 12:00:00.000Z",
+      "2018-04-01 08:00:00.000-04:00",
+    ] {
+      assert_eq!(parse_system_time_from_str(string).unwrap(), expected)
+    }
+  }
+
+  #[test]
+  fn parse_bare_local() {
+    let expected = UNIX_EPOCH + Duration::from_secs(1_522_584_000);
+    // Offset-less forms are interpreted as UTC.
+    assert_eq!(
+      parse_system_time_from_str("2018-04-01 12:00:00").unwrap(),
+      expected
+    );
+    assert_eq!(
+      parse_system_time_from_str("2018-04-01T12:00:00").unwrap(),
+      expected
+    );
+  }
+
+  #[test]
+  fn parse_rfc2822() {
+    let time = parse_system_time_from_rfc2822("Sun, 01 Apr 2018 12:04:17 -0500").unwrap();
+    let expected = UNIX_EPOCH + Duration::from_secs(1_522_602_257);
+    assert_eq!(time, expected)
+  }
+
+  #[test]
+  fn parse_rfc2822_negative_utc() {
+    // The `-0000` offset is accepted and interpreted as UTC.
+    let time = parse_system_time_from_rfc2822("Sun, 01 Apr 2018 12:04:17 -0000").unwrap();
+    let expected = UNIX_EPOCH + Duration::from_secs(1_522_584_257);
+    assert_eq!(time, expected)
+  }
 }
@@ -8,9 +8,54 @@ use chrono::DateTime;
 use chrono::SecondsFormat;
 
 
+/// The sub-second precision to use when emitting an RFC3339 time stamp.
+///
+/// This enumeration mirrors chrono's `SecondsFormat`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Precision {
+  /// Print seconds only, with no sub-second component.
+  Secs,
+  /// Print sub-seconds with millisecond precision.
+  Millis,
+  /// Print sub-seconds with microsecond precision.
+  Micros,
+  /// Print sub-seconds with nanosecond precision.
+  Nanos,
+  /// Automatically select the shortest of the above that represents the
+  /// value without loss, dropping trailing zero sub-second groups.
+  AutoSi,
+}
+
+impl From<Precision> for SecondsFormat {
+  fn from(precision: Precision) -> Self {
+    match precision {
+      Precision::Secs => SecondsFormat::Secs,
+      Precision::Millis => SecondsFormat::Millis,
+      Precision::Micros => SecondsFormat::Micros,
+      Precision::Nanos => SecondsFormat::Nanos,
+      Precision::AutoSi => SecondsFormat::AutoSi,
+    }
+  }
+}
+
+
+/// Print a `SystemTime` as an RFC3339 time stamp with the given
+/// sub-second `precision`.
+///
+/// When `use_z` is set the UTC offset is rendered as `Z` rather than
+/// `+00:00`.
+pub fn print_system_time_to_rfc3339_opts(
+  time: &SystemTime,
+  precision: Precision,
+  use_z: bool,
+) -> String {
+  DateTime::<Utc>::from(*time).to_rfc3339_opts(precision.into(), use_z)
+}
+
+
 /// Print a `SystemTime` as a RFC3339 time stamp.
 pub fn print_system_time_to_rfc3339(time: &SystemTime) -> String {
-  DateTime::<Utc>::from(*time).to_rfc3339_opts(SecondsFormat::Millis, true)
+  print_system_time_to_rfc3339_opts(time, Precision::Millis, true)
 }
 
 
@@ -18,7 +63,13 @@ pub fn print_system_time_to_rfc3339(time: &SystemTime) -> String {
 pub fn print_system_time_to_rfc3339_with_nanos(time: &SystemTime) -> String {
   // Rust's `SystemTime` internally work with nano seconds and so by
   // doing the same we hope to have no loss of information.
-  DateTime::<Utc>::from(*time).to_rfc3339_opts(SecondsFormat::Nanos, true)
+  print_system_time_to_rfc3339_opts(time, Precision::Nanos, true)
+}
+
+
+/// Print a `SystemTime` as an RFC 2822 time stamp.
+pub fn print_system_time_to_rfc2822(time: &SystemTime) -> String {
+  DateTime::<Utc>::from(*time).to_rfc2822()
 }
 
 
@@ -48,4 +99,38 @@ mod tests {
     let result = print_system_time_to_rfc3339_with_nanos(&time);
     assert_eq!(result, string)
   }
+
+
+  /// Check that the configurable precision behaves as expected.
+  #[test]
+  fn print_rfc3339_precision() {
+    let string = "2018-04-01T12:04:17.050Z";
+    let time = parse_system_time_from_str(string).unwrap();
+
+    assert_eq!(
+      print_system_time_to_rfc3339_opts(&time, Precision::Secs, true),
+      "2018-04-01T12:04:17Z"
+    );
+    assert_eq!(
+      print_system_time_to_rfc3339_opts(&time, Precision::Millis, true),
+      "2018-04-01T12:04:17.050Z"
+    );
+    assert_eq!(
+      print_system_time_to_rfc3339_opts(&time, Precision::AutoSi, true),
+      "2018-04-01T12:04:17.050Z"
+    );
+    assert_eq!(
+      print_system_time_to_rfc3339_opts(&time, Precision::Secs, false),
+      "2018-04-01T12:04:17+00:00"
+    );
+  }
+
+
+  /// Check that we can format a `SystemTime` as an RFC 2822 time stamp.
+  #[test]
+  fn print_rfc2822() {
+    let time = parse_system_time_from_str("2018-04-01T12:04:17.000Z").unwrap();
+    let result = print_system_time_to_rfc2822(&time);
+    assert_eq!(result, "Sun, 01 Apr 2018 12:04:17 +0000")
+  }
 }
@@ -6,7 +6,13 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 #[cfg(feature = "chrono-tz")]
-use chrono::offset::TimeZone as _;
+use chrono::offset::LocalResult;
+#[cfg(feature = "chrono-tz")]
+use chrono::offset::TimeZone;
+#[cfg(feature = "chrono-tz")]
+use chrono::offset::Utc;
+#[cfg(feature = "chrono-tz")]
+use chrono::DateTime;
 #[cfg(feature = "chrono-tz")]
 use chrono_tz::America::New_York;
 
@@ -18,8 +24,12 @@ use serde::Deserialize;
 
 use crate::parse::parse_system_time_from_str_impl;
 use crate::parse::DATE_PARSE_FNS;
+use crate::parse::RFC2822_PARSE_FNS;
 use crate::parse::TIME_PARSE_FNS;
+use crate::print::print_system_time_to_rfc2822;
 use crate::print::print_system_time_to_rfc3339;
+use crate::print::print_system_time_to_rfc3339_opts;
+use crate::print::Precision;
 
 
 /// Deserialize a time stamp as a `SystemTime`.
@@ -49,6 +59,17 @@ where
 }
 
 
+/// Deserialize a `SystemTime` from an RFC 2822 time stamp.
+pub fn system_time_from_rfc2822<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let time = String::deserialize(deserializer)?;
+  parse_system_time_from_str_impl(&time, &RFC2822_PARSE_FNS)
+    .ok_or_else(|| Error::invalid_value(Unexpected::Str(&time), &"an RFC 2822 time stamp string"))
+}
+
+
 /// Deserialize a `SystemTime` from a date.
 pub fn system_time_from_date_str<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
 where
@@ -83,22 +104,104 @@ where
 }
 
 
+/// Offset the epoch by the given (possibly negative) `duration`,
+/// reaching back before it for negative values.
+fn system_time_from_signed(negative: bool, duration: Duration) -> SystemTime {
+  if negative {
+    UNIX_EPOCH - duration
+  } else {
+    UNIX_EPOCH + duration
+  }
+}
+
+
+/// Deserialize a `SystemTime` from a possibly negative UNIX time stamp,
+/// allowing times before 1970-01-01 (e.g. historical dates).
+pub fn system_time_from_signed_secs<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let seconds = i64::deserialize(deserializer)?;
+  Ok(system_time_from_signed(
+    seconds < 0,
+    Duration::from_secs(seconds.unsigned_abs()),
+  ))
+}
+
+
+/// Deserialize a `SystemTime` from a possibly negative timestamp
+/// containing the milliseconds since 1970-01-01, allowing times before
+/// the epoch.
+pub fn system_time_from_signed_millis<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let ms = i64::deserialize(deserializer)?;
+  Ok(system_time_from_signed(
+    ms < 0,
+    Duration::from_millis(ms.unsigned_abs()),
+  ))
+}
+
+
+/// Resolve a wall-clock naive date time in the given time `zone` into a
+/// concrete instant.
+///
+/// Unlike a bare `.unwrap()` on the `LocalResult`, this does not panic
+/// on the ambiguous local times produced by a daylight-saving fall-back
+/// (the repeated hour) nor on the nonexistent times produced by a
+/// spring-forward gap:
+///   * for an ambiguous time we pick the earlier of the two instants,
+///   * for a nonexistent time we fall back to interpreting the digits
+///     as if they were UTC, which keeps the round-trip total.
+#[cfg(feature = "chrono-tz")]
+fn resolve_local<Tz>(zone: &Tz, naive: chrono::NaiveDateTime) -> DateTime<Tz>
+where
+  Tz: TimeZone,
+{
+  match zone.from_local_datetime(&naive) {
+    LocalResult::Single(time) => time,
+    LocalResult::Ambiguous(earliest, _latest) => earliest,
+    LocalResult::None => zone.from_utc_datetime(&naive),
+  }
+}
+
+
 /// Deserialize a `SystemTime` from a timestamp containing the
-/// milliseconds since 1970-01-01 in the New York time zone.
+/// milliseconds since 1970-01-01, reinterpreting the wall-clock reading
+/// as being in `zone`.
+///
+/// Correctly handles the ambiguous and nonexistent local times that
+/// arise around daylight-saving transitions (see [`resolve_local`]).
 #[cfg(feature = "chrono-tz")]
-pub fn system_time_from_millis_in_new_york<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+pub fn system_time_from_millis_in_zone<'de, D, Tz>(
+  zone: &Tz,
+  deserializer: D,
+) -> Result<SystemTime, D::Error>
 where
   D: Deserializer<'de>,
+  Tz: TimeZone,
 {
   let time = system_time_from_millis(deserializer)?;
-  let naive_time = DateTime::<Utc>::from(time).naive_local();
-  let ny_time = New_York.from_utc_datetime(&naive_time);
-  let utc_time = Utc.from_local_datetime(&ny_time.naive_local()).unwrap();
+  let naive_time = DateTime::<Utc>::from(time).naive_utc();
+  let zoned = zone.from_utc_datetime(&naive_time);
+  let utc_time = resolve_local(&Utc, zoned.naive_local());
 
   Ok(SystemTime::from(utc_time))
 }
 
 
+/// Deserialize a `SystemTime` from a timestamp containing the
+/// milliseconds since 1970-01-01 in the New York time zone.
+#[cfg(feature = "chrono-tz")]
+pub fn system_time_from_millis_in_new_york<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  system_time_from_millis_in_zone(&New_York, deserializer)
+}
+
+
 /// Serialize a `SystemTime` into a RFC3339 time stamp.
 pub fn system_time_to_rfc3339<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -108,6 +211,26 @@ where
   serializer.serialize_str(&string)
 }
 
+/// Serialize a `SystemTime` into a whole-second RFC3339 time stamp,
+/// i.e., without the mandatory `.000` sub-second suffix that
+/// [`system_time_to_rfc3339`] always emits.
+pub fn system_time_to_rfc3339_secs<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  let string = print_system_time_to_rfc3339_opts(time, Precision::Secs, true);
+  serializer.serialize_str(&string)
+}
+
+/// Serialize a `SystemTime` into an RFC 2822 time stamp.
+pub fn system_time_to_rfc2822<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  let string = print_system_time_to_rfc2822(time);
+  serializer.serialize_str(&string)
+}
+
 /// Serialize an optional `SystemTime` into a RFC3339 time stamp.
 pub fn optional_system_time_to_rfc3339<S>(
   time: &Option<SystemTime>,
@@ -137,6 +260,59 @@ where
 }
 
 
+/// Serialize a `SystemTime` into a possibly negative UNIX time stamp,
+/// supporting times before 1970-01-01.
+pub fn system_time_to_signed_secs<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  let secs = match time.duration_since(UNIX_EPOCH) {
+    Ok(duration) => duration.as_secs() as i64,
+    // The time precedes the epoch; `duration_since` the other way round
+    // yields how far back it reaches.
+    Err(_) => -(UNIX_EPOCH.duration_since(*time).unwrap().as_secs() as i64),
+  };
+  serializer.serialize_i64(secs)
+}
+
+
+/// Serialize a `SystemTime` into a possibly negative timestamp
+/// containing the milliseconds since 1970-01-01, supporting times
+/// before the epoch.
+pub fn system_time_to_signed_millis<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  let millis = match time.duration_since(UNIX_EPOCH) {
+    Ok(duration) => duration.as_millis() as i64,
+    Err(_) => -(UNIX_EPOCH.duration_since(*time).unwrap().as_millis() as i64),
+  };
+  serializer.serialize_i64(millis)
+}
+
+
+/// Serialize a `SystemTime` into a timestamp containing the
+/// milliseconds since 1970-01-01, with the stored wall-clock reading
+/// taken to be in `zone`.
+///
+/// This is the inverse of [`system_time_from_millis_in_zone`] and shares
+/// its handling of ambiguous and nonexistent local times.
+#[cfg(feature = "chrono-tz")]
+pub fn system_time_to_millis_in_zone<S, Tz>(
+  zone: &Tz,
+  time: &SystemTime,
+  serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+  Tz: TimeZone,
+{
+  let utc_time = DateTime::<Utc>::from(*time);
+  let zoned = resolve_local(zone, utc_time.naive_utc());
+  system_time_to_millis(&SystemTime::from(zoned), serializer)
+}
+
+
 /// Serialize a `SystemTime` into a timestamp containing the
 /// milliseconds since 1970-01-01 in New York.
 #[cfg(feature = "chrono-tz")]
@@ -147,9 +323,7 @@ pub fn system_time_to_millis_in_new_york<S>(
 where
   S: Serializer,
 {
-  let utc_time = DateTime::<Utc>::from(*time);
-  let ny_time = New_York.from_local_datetime(&utc_time.naive_utc()).unwrap();
-  system_time_to_millis(&SystemTime::from(ny_time), serializer)
+  system_time_to_millis_in_zone(&New_York, time, serializer)
 }
 
 
@@ -205,6 +379,23 @@ mod tests {
   }
 
 
+  #[derive(Debug, Deserialize, Serialize)]
+  struct Rfc2822Time {
+    #[serde(
+      deserialize_with = "system_time_from_rfc2822",
+      serialize_with = "system_time_to_rfc2822",
+    )]
+    time: SystemTime,
+  }
+
+  #[test]
+  fn deserialize_serialize_system_time_rfc2822() {
+    let json = r#"{"time":"Sun, 01 Apr 2018 12:04:17 +0000"}"#;
+    let time = from_json::<Rfc2822Time>(json).unwrap();
+    assert_eq!(time.time, UNIX_EPOCH + Duration::from_secs(1522584257));
+    assert_eq!(to_json(&time).unwrap(), json);
+  }
+
   #[derive(Debug, Deserialize, Serialize)]
   struct OtherTime {
     #[serde(
@@ -229,6 +420,60 @@ mod tests {
     assert_eq!(json, r#"{"time":"2018-12-06T20:47:00.000Z"}"#);
   }
 
+  #[derive(Debug, Deserialize, Serialize)]
+  struct SecsTime {
+    #[serde(
+      deserialize_with = "system_time_from_secs",
+      serialize_with = "system_time_to_rfc3339_secs",
+    )]
+    time: SystemTime,
+  }
+
+  #[test]
+  fn serialize_system_time_to_rfc3339_secs() {
+    let time = SecsTime {
+      time: UNIX_EPOCH + Duration::from_secs(1544129220),
+    };
+    let json = to_json(&time).unwrap();
+    assert_eq!(json, r#"{"time":"2018-12-06T20:47:00Z"}"#);
+  }
+
+  #[derive(Debug, Deserialize, Serialize)]
+  struct SignedTime {
+    #[serde(
+      deserialize_with = "system_time_from_signed_secs",
+      serialize_with = "system_time_to_signed_secs",
+    )]
+    time: SystemTime,
+  }
+
+  #[test]
+  fn deserialize_serialize_signed_secs_before_epoch() {
+    // 1969-07-21T00:00:00Z is 164 days before the epoch.
+    let expected = UNIX_EPOCH - Duration::from_secs(14_169_600);
+    let time = from_json::<SignedTime>(r#"{"time": -14169600}"#).unwrap();
+    assert_eq!(time.time, expected);
+    assert_eq!(to_json(&time).unwrap(), r#"{"time":-14169600}"#);
+  }
+
+  #[derive(Debug, Deserialize, Serialize)]
+  struct SignedMsTime {
+    #[serde(
+      deserialize_with = "system_time_from_signed_millis",
+      serialize_with = "system_time_to_signed_millis",
+    )]
+    time: SystemTime,
+  }
+
+  #[test]
+  fn deserialize_serialize_signed_millis_round_trip() {
+    for raw in &["-14169600000", "0", "1517461200000"] {
+      let json = format!(r#"{{"time": {}}}"#, raw);
+      let time = from_json::<SignedMsTime>(&json).unwrap();
+      assert_eq!(to_json(&time).unwrap(), format!(r#"{{"time":{}}}"#, raw));
+    }
+  }
+
   #[derive(Debug, Deserialize, Serialize)]
   struct MsTime {
     #[serde(
@@ -280,4 +525,57 @@ mod tests {
     let time = from_json::<MsTimeNY>(&json).unwrap();
     assert_eq!(time.time, expected);
   }
+
+
+  #[cfg(feature = "chrono-tz")]
+  fn london_from_millis<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    system_time_from_millis_in_zone(&chrono_tz::Europe::London, deserializer)
+  }
+
+  #[cfg(feature = "chrono-tz")]
+  fn london_to_millis<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    system_time_to_millis_in_zone(&chrono_tz::Europe::London, time, serializer)
+  }
+
+  #[derive(Debug, Deserialize, Serialize)]
+  #[cfg(feature = "chrono-tz")]
+  struct MsTimeLondon {
+    #[serde(
+      deserialize_with = "london_from_millis",
+      serialize_with = "london_to_millis",
+    )]
+    time: SystemTime,
+  }
+
+  #[test]
+  #[cfg(feature = "chrono-tz")]
+  fn deserialize_serialize_system_time_millis_in_zone() {
+    // 2018-02-01T00:00:00+00:00 wall-clock in London (GMT in winter).
+    let time = from_json::<MsTimeLondon>(r#"{"time": 1517443200000}"#).unwrap();
+    let expected = parse_system_time_from_str("2018-02-01T00:00:00.000Z").unwrap();
+    assert_eq!(time.time, expected);
+
+    let json = to_json::<MsTimeLondon>(&time).unwrap();
+    let time = from_json::<MsTimeLondon>(&json).unwrap();
+    assert_eq!(time.time, expected);
+  }
+
+  #[test]
+  #[cfg(feature = "chrono-tz")]
+  fn serialize_system_time_millis_in_zone_ambiguous_hour_does_not_panic() {
+    // A naive-UTC wall clock of 2020-11-01T01:30:00 falls into the
+    // repeated hour of the New York daylight-saving fall-back, where
+    // `from_local_datetime` returns `LocalResult::Ambiguous`. Serializing
+    // it must not panic.
+    let time = MsTimeNY {
+      time: parse_system_time_from_str("2020-11-01T01:30:00.000Z").unwrap(),
+    };
+    let _ = to_json(&time).unwrap();
+  }
 }
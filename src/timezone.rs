@@ -12,11 +12,11 @@ pub enum Offset {
   /// An offset from UTC (in seconds) in the western hemisphere.
   ///
   /// E.g., a value of `1 * 60 * 60` maps to UTC-01:00
-  West(u16),
+  West(u32),
   /// An offset from UTC (in seconds) in the eastern hemisphere.
   ///
   /// E.g., a value of `1 * 60 * 60` maps to UTC+01:00
-  East(u16),
+  East(u32),
 }
 
 
@@ -32,6 +32,16 @@ pub trait TimeZone {
       Offset::East(offset) => time + Duration::from_secs(offset.into()),
     }
   }
+
+  /// Correct a system time by subtracting our offset, i.e., the inverse
+  /// of [`add`](TimeZone::add).
+  fn sub(time: SystemTime) -> SystemTime {
+    match Self::OFFSET {
+      Offset::None => time,
+      Offset::West(offset) => time + Duration::from_secs(offset.into()),
+      Offset::East(offset) => time - Duration::from_secs(offset.into()),
+    }
+  }
 }
 
 
@@ -51,6 +61,77 @@ impl TimeZone for EST {
 }
 
 
+/// A timezone offset discovered at runtime.
+///
+/// Unlike the compile-time [`TimeZone`] implementations [`UTC`] & [`EST`],
+/// this carries a signed number of seconds east of UTC and so can
+/// express the full range of real-world offsets (up to ±24:00), as well
+/// as offsets parsed from a time stamp string via [`parse_offset`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedOffset {
+  /// The offset east of UTC, in seconds; negative west of UTC.
+  secs: i32,
+}
+
+impl FixedOffset {
+  /// The largest offset magnitude we accept (24 hours).
+  const MAX_SECS: i32 = 24 * 60 * 60;
+
+  /// Create a `FixedOffset` of `secs` seconds east of UTC (negative for
+  /// the western hemisphere), returning `None` if the magnitude exceeds
+  /// 24 hours.
+  pub fn new(secs: i32) -> Option<Self> {
+    if secs.abs() <= Self::MAX_SECS {
+      Some(Self { secs })
+    } else {
+      None
+    }
+  }
+
+  /// The offset in seconds east of UTC.
+  pub fn seconds(&self) -> i32 {
+    self.secs
+  }
+
+  /// Correct a system time by adding our offset.
+  pub fn add(&self, time: SystemTime) -> SystemTime {
+    if self.secs >= 0 {
+      time + Duration::from_secs(self.secs as u64)
+    } else {
+      time - Duration::from_secs(self.secs.unsigned_abs().into())
+    }
+  }
+
+  /// Correct a system time by subtracting our offset, i.e., the inverse
+  /// of [`add`](FixedOffset::add).
+  pub fn sub(&self, time: SystemTime) -> SystemTime {
+    if self.secs >= 0 {
+      time - Duration::from_secs(self.secs as u64)
+    } else {
+      time + Duration::from_secs(self.secs.unsigned_abs().into())
+    }
+  }
+}
+
+
+/// Parse an RFC3339 style UTC offset, such as `-05:00` or `+09:30`, into
+/// a [`FixedOffset`].
+///
+/// The scanning is delegated to chrono, so exactly the forms chrono
+/// recognizes as an offset are accepted.
+#[cfg(feature = "chrono")]
+pub fn parse_offset(offset: &str) -> Option<FixedOffset> {
+  use chrono::DateTime;
+  use chrono::Offset as _;
+
+  // Reuse chrono's RFC3339 scanner by pinning the offset onto an
+  // otherwise fixed date-time and reading back the parsed offset.
+  let pinned = format!("1970-01-01T00:00:00{}", offset);
+  let datetime = DateTime::parse_from_rfc3339(&pinned).ok()?;
+  FixedOffset::new(datetime.offset().fix().local_minus_utc())
+}
+
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -67,4 +148,33 @@ mod tests {
     assert_eq!(EST::add(est_time), expected);
     assert_eq!(UTC::add(utc_time), expected);
   }
+
+
+  #[test]
+  fn fixed_offset_range() {
+    assert!(FixedOffset::new(0).is_some());
+    assert!(FixedOffset::new(20 * 60 * 60).is_some());
+    assert!(FixedOffset::new(-(20 * 60 * 60)).is_some());
+    // Just beyond 24 hours is rejected.
+    assert!(FixedOffset::new(24 * 60 * 60 + 1).is_none());
+    assert!(FixedOffset::new(-(24 * 60 * 60 + 1)).is_none());
+  }
+
+  #[test]
+  fn fixed_offset_correction() {
+    let est_time = parse_system_time_from_str("2018-04-01T08:00:37.000-05:00").unwrap();
+    let expected = parse_system_time_from_str("2018-04-01T08:00:37.000Z").unwrap();
+
+    let offset = FixedOffset::new(-(5 * 60 * 60)).unwrap();
+    assert_eq!(offset.add(est_time), expected);
+    assert_eq!(offset.sub(expected), est_time);
+  }
+
+  #[test]
+  fn parse_runtime_offset() {
+    assert_eq!(parse_offset("-05:00").unwrap().seconds(), -(5 * 60 * 60));
+    assert_eq!(parse_offset("+09:30").unwrap().seconds(), 9 * 60 * 60 + 30 * 60);
+    assert_eq!(parse_offset("+00:00").unwrap().seconds(), 0);
+    assert!(parse_offset("not an offset").is_none());
+  }
 }